@@ -23,6 +23,41 @@ use gpui::prelude::*; // Import common GPUI traits like Render, IntoElement
 use gpui::*; // Import GPUI types and functions
 use std::time::Instant; // For tracking cursor blink timing
 
+fn main() {
+    Application::new().run(|cx: &mut App| {
+        let theme_watcher = ThemeWatcher::new(cx);
+        let platform = theme_watcher.read(cx).platform;
+        // Every platform theme already defaults to `Opaque`; routing it
+        // through `with_background_appearance` (rather than reading the
+        // field directly) is what lets a future "transparent/blurred
+        // window" setting flip this to `Transparent`/`Blurred` without
+        // touching this call site.
+        let theme = theme_watcher
+            .read(cx)
+            .theme
+            .clone()
+            .with_background_appearance(WindowBackgroundAppearance::Opaque);
+
+        let bounds = Bounds::centered(None, size(px(420.0), px(620.0)), cx);
+        cx.open_window(theme.window_options(bounds), |_window, cx| {
+            cx.new(|cx| {
+                let title_bar = TitleBar::new(platform, theme);
+                // Keep the title bar's colors in sync with the OS: every
+                // time `theme_watcher` refreshes in response to an
+                // appearance/accent notification, copy its theme over and
+                // re-render.
+                cx.observe(&theme_watcher, |title_bar: &mut TitleBar, watcher, cx| {
+                    title_bar.theme = watcher.read(cx).theme.clone();
+                    cx.notify();
+                })
+                .detach();
+                title_bar
+            })
+        })
+        .expect("failed to open the biorhythm window");
+    });
+}
+
 // ======================================================
 // PLATFORM DETECTION & THEMING
 // ======================================================
@@ -65,6 +100,11 @@ struct Theme {
     titlebar_border: Hsla,
     titlebar_height: f32,
 
+    // Whether the window renders as a solid surface, a translucent one, or
+    // (macOS only) one backed by system vibrancy. Threaded straight into
+    // `WindowOptions::window_background` when the window is created.
+    background_appearance: WindowBackgroundAppearance,
+
     // Traffic lights (MacOS) or window controls
     close_button_bg: Hsla,
     close_button_corner: Hsla,
@@ -81,6 +121,10 @@ struct Theme {
     input_border: Hsla,
     input_border_focused: Hsla,
     input_text: Hsla,
+    // Contrast-picked (via `ideal_text_on`) color for a label/caption that
+    // renders directly on the accent-filled focus ring, e.g. a floating
+    // label or validation badge anchored to `input_border_focused`.
+    input_label_focused: Hsla,
 
     // Buttons
     button_primary_bg: Hsla,
@@ -97,6 +141,18 @@ struct Theme {
     text_error: Hsla,
 }
 
+// The named colors a GTK theme exposes in its CSS, looked up straight off a
+// throwaway `StyleContext` rather than guessing from the theme's name. Any
+// field is `None` if the active theme doesn't define it, which
+// `Theme::linux_with_preferences` falls back on.
+#[cfg(target_os = "linux")]
+#[derive(Default)]
+struct GtkPalette {
+    accent: Option<u32>,
+    fg: Option<u32>,
+    base: Option<u32>,
+}
+
 impl Theme {
     fn new(platform: Platform) -> Self {
         match platform {
@@ -122,15 +178,16 @@ impl Theme {
             let dark_name: id = NSAppearanceNameVibrantDark;
             let best_match: id =
                 msg_send![appearance, bestMatchFromAppearancesWithNames: &[dark_name]];
+            let is_dark = best_match == dark_name;
 
             // Try to get system accent color
-            let accent_color: Self::get_macos_accent_color();
+            let accent_color = Self::get_macos_accent_color();
 
             Self::macos_with_preferences(is_dark, accent_color)
         }
     }
 
-    #[cfg(not(target_os = "macos"))]
+    #[cfg(target_os = "macos")]
     fn get_macos_accent_color() -> Option<u32> {
         use cocoa::base::id;
         use objc::{class, msg_send, sel, sel_impl};
@@ -153,7 +210,7 @@ impl Theme {
             let mut r: f64 = 0.0;
             let mut g: f64 = 0.0;
             let mut b: f64 = 0.0;
-            let _: () = msg_send![rgb_color, getRed: &mut r green: &mut g blue: &mut b alpha: std::ptr::mull_mut::<f64>()];
+            let _: () = msg_send![rgb_color, getRed: &mut r green: &mut g blue: &mut b alpha: std::ptr::null_mut::<f64>()];
 
             // Convert to hex
             let r_int = (r * 255.0) as u32;
@@ -171,7 +228,7 @@ impl Theme {
     fn macos_with_preferences(is_dark: bool, accent_color: Option<u32>) -> Self {
         // Use system accent color if available, otherwise default to macOS blue
         let accent = accent_color.unwrap_or(0x007AFF);
-        let accent_hover = Self::darken_color(accent, 0.9);
+        let accent_hover = Self::accent_hover_color(accent);
 
         if is_dark {
             // Dark mode colors
@@ -179,6 +236,7 @@ impl Theme {
                 titlebar_bg: rgb(0x202020).into(),
                 titlebar_border: rgb(0x1E1E1E).into(),
                 titlebar_height: 22.0,
+                background_appearance: WindowBackgroundAppearance::Opaque,
 
                 close_button_bg: rgb(0xFF5F57).into(),
                 close_button_corner: rgb(0xE04943).into(),
@@ -192,11 +250,12 @@ impl Theme {
                 input_bg: rgb(0x2D2D2D).into(),
                 input_border: rgb(0x404040).into(),
                 input_border_focused: rgb(accent).into(),
+                input_label_focused: Self::ideal_text_on(accent),
                 input_text: rgb(0xFFFFFF).into(),
 
                 button_primary_bg: rgb(accent).into(),
                 button_primary_bg_hover: rgb(accent_hover).into(),
-                button_primary_text: rgb(0xFFFFFF).into(),
+                button_primary_text: Self::ideal_text_on(accent),
                 button_secondary_bg: rgb(0x2D2D2D).into(),
                 button_secondary_bg_hover: rgb(0x383838).into(),
                 button_secondary_text: rgb(0xFFFFFF).into(),
@@ -212,6 +271,7 @@ impl Theme {
                 titlebar_bg: rgb(0xE8E8E8).into(),
                 titlebar_border: rgb(0xD0D0D0).into(),
                 titlebar_height: 22.0,
+                background_appearance: WindowBackgroundAppearance::Opaque,
 
                 close_button_bg: rgb(0xFF5F57).into(),
                 close_button_corner: rgb(0xE04943).into(),
@@ -225,11 +285,12 @@ impl Theme {
                 input_bg: rgb(0xFFFFFF).into(),
                 input_border: rgb(0xCCCCCC).into(),
                 input_border_focused: rgb(accent).into(),
+                input_label_focused: Self::ideal_text_on(accent),
                 input_text: rgb(0x000000).into(),
 
                 button_primary_bg: rgb(accent).into(),
                 button_primary_bg_hover: rgb(accent_hover).into(),
-                button_primary_text: rgb(0xFFFFFF).into(),
+                button_primary_text: Self::ideal_text_on(accent),
                 button_secondary_bg: rgb(0xFFFFFF).into(),
                 button_secondary_bg_hover: rgb(0xF8F8F8).into(),
                 button_secondary_text: rgb(0x000000).into(),
@@ -255,6 +316,90 @@ impl Theme {
         (r_dark << 16) | (g_dark << 8) | b_dark
     }
 
+    // Helper function to lighten a color (sibling of `darken_color`, used so
+    // hover states derived from very dark accents don't just vanish into
+    // the background).
+    fn lighten_color(color: u32, factor: f32) -> u32 {
+        let r = ((color >> 16) & 0xFF) as f32;
+        let g = ((color >> 8) & 0xFF) as f32;
+        let b = (color & 0xFF) as f32;
+
+        let r_light = (r + (255.0 - r) * factor).min(255.0) as u32;
+        let g_light = (g + (255.0 - g) * factor).min(255.0) as u32;
+        let b_light = (b + (255.0 - b) * factor).min(255.0) as u32;
+
+        (r_light << 16) | (g_light << 8) | b_light
+    }
+
+    // WCAG relative luminance of a packed `0xRRGGBB` color.
+    fn relative_luminance(color: u32) -> f32 {
+        let linearize = |channel: u32| -> f32 {
+            let c = channel as f32 / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+
+        let r = linearize((color >> 16) & 0xFF);
+        let g = linearize((color >> 8) & 0xFF);
+        let b = linearize(color & 0xFF);
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    // Picks black or white text for maximum contrast against `color`, per
+    // the WCAG relative luminance formula. Used anywhere text sits directly
+    // on top of the user's (arbitrary) system accent color, since a pastel
+    // accent makes hardcoded white text illegible.
+    fn ideal_text_on(color: u32) -> Hsla {
+        if Self::relative_luminance(color) > 0.179 {
+            rgb(0x1A1A1A).into()
+        } else {
+            rgb(0xFFFFFF).into()
+        }
+    }
+
+    // Derives a hover shade for an accent color: darkens light accents as
+    // before, but lightens already-dark accents instead, so a very dark
+    // system accent doesn't get a hover state that's indistinguishable
+    // from its resting state.
+    fn accent_hover_color(accent: u32) -> u32 {
+        if Self::relative_luminance(accent) > 0.179 {
+            Self::darken_color(accent, 0.9)
+        } else {
+            Self::lighten_color(accent, 0.15)
+        }
+    }
+
+    // Switches this theme to a transparent or blurred window surface,
+    // giving `background` the alpha the requested appearance needs. GPUI's
+    // macOS platform layer installs the `NSVisualEffectView` vibrancy
+    // surface itself whenever `WindowOptions::window_background` is
+    // `Blurred`; content just needs to stop painting an opaque backdrop so
+    // the renderer's clear color lets that vibrancy show through.
+    fn with_background_appearance(mut self, appearance: WindowBackgroundAppearance) -> Self {
+        self.background_appearance = appearance;
+        self.background = match appearance {
+            WindowBackgroundAppearance::Opaque => self.background.opacity(1.0),
+            WindowBackgroundAppearance::Transparent => self.background.opacity(0.85),
+            WindowBackgroundAppearance::Blurred => self.background.opacity(0.6),
+        };
+        self
+    }
+
+    // Builds the `WindowOptions` this theme implies, so `main()` can open a
+    // window whose chrome matches `background_appearance` without
+    // duplicating the Opaque/Transparent/Blurred branching at the call
+    // site.
+    fn window_options(&self, bounds: Bounds<Pixels>) -> WindowOptions {
+        WindowOptions {
+            window_bounds: Some(WindowBounds::Windowed(bounds)),
+            window_background: self.background_appearance,
+            ..Default::default()
+        }
+    }
+
     // Windows system theme detection
     #[cfg(target_os = "windows")]
     fn windows_system() -> Self {
@@ -272,29 +417,95 @@ impl Theme {
                     None
                 };
 
-            // Detect dark mode (simplified - in reality would check registry)
-            // For now, defaulting to light mode
-            let is_dark = false;
+            // `AppsUseLightTheme == 0` is how Settings > Personalization >
+            // Colors actually stores the app dark-mode choice; there is no
+            // public API for it.
+            let is_dark = Self::read_personalize_dword("AppsUseLightTheme")
+                .map(|value| value == 0)
+                .unwrap_or(false);
+
+            // `ColorPrevalence == 1` means the user asked the accent color
+            // to tint title bars and window borders too.
+            let accent_on_titlebar =
+                Self::read_personalize_dword("ColorPrevalence").unwrap_or(0) == 1;
+
+            Self::windows_with_preferences(is_dark, accent_color, accent_on_titlebar)
+        }
+    }
+
+    // Reads a DWORD from
+    // `HKCU\Software\Microsoft\Windows\CurrentVersion\Themes\Personalize`,
+    // the key Settings > Personalization writes to.
+    #[cfg(target_os = "windows")]
+    fn read_personalize_dword(value_name: &str) -> Option<u32> {
+        use windows::core::PCWSTR;
+        use windows::Win32::System::Registry::{RegGetValueW, HKEY_CURRENT_USER, RRF_RT_REG_DWORD};
+
+        let subkey: Vec<u16> =
+            "Software\\Microsoft\\Windows\\CurrentVersion\\Themes\\Personalize\0"
+                .encode_utf16()
+                .collect();
+        let value: Vec<u16> = value_name
+            .encode_utf16()
+            .chain(std::iter::once(0))
+            .collect();
+
+        let mut data: u32 = 0;
+        let mut data_len = std::mem::size_of::<u32>() as u32;
 
-            Self::windows_with_preferences(is_dark, accent_color)
+        unsafe {
+            let status = RegGetValueW(
+                HKEY_CURRENT_USER,
+                PCWSTR(subkey.as_ptr()),
+                PCWSTR(value.as_ptr()),
+                RRF_RT_REG_DWORD,
+                None,
+                Some(&mut data as *mut u32 as *mut _),
+                Some(&mut data_len),
+            );
+            status.is_ok().then_some(data)
         }
     }
 
     #[cfg(not(target_os = "windows"))]
     fn windows_system() -> Self {
-        Self::windows_with_preferences(false, None)
+        Self::windows_with_preferences(false, None, false)
     }
 
-    fn windows_with_preferences(is_dark: bool, accent_color: Option<u32>) -> Self {
+    fn windows_with_preferences(
+        is_dark: bool,
+        accent_color: Option<u32>,
+        accent_on_titlebar: bool,
+    ) -> Self {
         let accent = accent_color.unwrap_or(0x0078D4);
-        let accent_hover = Self::darken_color(accent, 0.9);
+        let accent_hover = Self::accent_hover_color(accent);
+
+        // When the accent tints the titlebar, the titlebar and its border
+        // take the accent color. The window-control button colors below
+        // stay the plain light/dark ones either way; only text drawn on
+        // accent fills (`ideal_text_on`) follows the accent's luminance.
+        let titlebar_bg = if accent_on_titlebar {
+            accent
+        } else if is_dark {
+            0x202020
+        } else {
+            0xF0F0F0
+        };
+        let titlebar_border = if accent_on_titlebar {
+            Self::darken_color(accent, 0.85)
+        } else if is_dark {
+            0x1A1A1A
+        } else {
+            0xDFDFDF
+        };
 
         if is_dark {
             // Windows dark mode colors
             Self {
-                titlebar_bg: rgb(0x202020).into(),
-                titlebar_border: rgb(0x1A1A1A).into(),
+                titlebar_bg: rgb(titlebar_bg).into(),
+                titlebar_border: rgb(titlebar_border).into(),
                 titlebar_height: 32.0,
+                background_appearance: WindowBackgroundAppearance::Opaque,
 
                 close_button_bg: rgb(0xE81123).into(),
                 close_button_corner: rgb(0xC50F1F).into(),
@@ -308,11 +519,12 @@ impl Theme {
                 input_bg: rgb(0x2D2D2D).into(),
                 input_border: rgb(0x404040).into(),
                 input_border_focused: rgb(accent).into(),
+                input_label_focused: Self::ideal_text_on(accent),
                 input_text: rgb(0xFFFFFF).into(),
 
                 button_primary_bg: rgb(accent).into(),
                 button_primary_bg_hover: rgb(accent_hover).into(),
-                button_primary_text: rgb(0xFFFFFF).into(),
+                button_primary_text: Self::ideal_text_on(accent),
                 button_secondary_bg: rgb(0x2D2D2D).into(),
                 button_secondary_bg_hover: rgb(0x383838).into(),
                 button_secondary_text: rgb(0xFFFFFF).into(),
@@ -325,9 +537,10 @@ impl Theme {
         } else {
             // Windows light mode colors
             Self {
-                titlebar_bg: rgb(0xF0F0F0).into(),
-                titlebar_border: rgb(0xDFDFDF).into(),
+                titlebar_bg: rgb(titlebar_bg).into(),
+                titlebar_border: rgb(titlebar_border).into(),
                 titlebar_height: 32.0,
+                background_appearance: WindowBackgroundAppearance::Opaque,
 
                 close_button_bg: rgb(0xE81123).into(),
                 close_button_corner: rgb(0xC50F1F).into(),
@@ -341,11 +554,12 @@ impl Theme {
                 input_bg: rgb(0xFFFFFF).into(),
                 input_border: rgb(0x8A8A8A).into(),
                 input_border_focused: rgb(accent).into(),
+                input_label_focused: Self::ideal_text_on(accent),
                 input_text: rgb(0x000000).into(),
 
                 button_primary_bg: rgb(accent).into(),
                 button_primary_bg_hover: rgb(accent_hover).into(),
-                button_primary_text: rgb(0xFFFFFF).into(),
+                button_primary_text: Self::ideal_text_on(accent),
                 button_secondary_bg: rgb(0xFFFFFF).into(),
                 button_secondary_bg_hover: rgb(0xF5F5F5).into(),
                 button_secondary_text: rgb(0x000000).into(),
@@ -361,40 +575,60 @@ impl Theme {
     // Linux system theme detection
     #[cfg(target_os = "linux")]
     fn linux_system() -> Self {
-        use gtk::prelude::*;
-        use gtk::{Settings, StyleContext};
-
-        // Try to read GTK theme colors
-        let accent_color = Self::get_gtk_accent_color();
         let is_dark = Self::get_gtk_dark_mode();
+        let palette = Self::get_gtk_palette();
 
-        Self::linux_with_preferences(is_dark, accent_color)
+        Self::linux_with_preferences(is_dark, palette)
     }
 
     #[cfg(target_os = "linux")]
-    fn get_gtk_accent_color() -> Option<u32> {
-        // Initialize GTK if not already done
+    fn get_gtk_palette() -> GtkPalette {
+        use gtk::prelude::*;
+        use gtk::{StyleContext, WidgetPath};
+
         if gtk::init().is_err() {
-            return None;
+            return GtkPalette::default();
         }
 
-        // Try to get the theme accent color
-        // This is a simplified example; real GTK themes may vary
-        let settings = Settings::default()?;
-        let theme_name = settings.gtk_theme_name()?;
-
-        // Map known themes to their accent colors
-        if theme_name.contains("Adwaita") {
-            Some(0x3584E4) // Default Adwaita blue
-        } else if theme_name.contains("elementary") {
-            Some(0x3689E6) // Elementary OS blue
-        } else {
-            None
+        // An offscreen style context for a generic widget gives us access
+        // to the active theme's named `@theme_*`/`@accent_*` CSS colors
+        // without ever creating a real, visible widget - but only once
+        // it's attached to the default screen. Without `set_screen`, GTK
+        // resolves the context against its built-in fallback theme
+        // (Adwaita) instead of whatever the user actually has set, which
+        // is exactly the generic-blue problem this is meant to fix.
+        let Some(screen) = gtk::gdk::Screen::default() else {
+            return GtkPalette::default();
+        };
+
+        let context = StyleContext::new();
+        context.set_screen(&screen);
+        let mut path = WidgetPath::new();
+        path.append_type(gtk::Widget::static_type());
+        context.set_path(&path);
+
+        let lookup = |name: &str| context.lookup_color(name).map(Self::gdk_rgba_to_u32);
+
+        GtkPalette {
+            accent: lookup("theme_selected_bg_color").or_else(|| lookup("accent_bg_color")),
+            fg: lookup("theme_fg_color"),
+            base: lookup("theme_base_color"),
         }
     }
 
+    #[cfg(target_os = "linux")]
+    fn gdk_rgba_to_u32(color: gtk::gdk::RGBA) -> u32 {
+        let r = (color.red() * 255.0).round() as u32;
+        let g = (color.green() * 255.0).round() as u32;
+        let b = (color.blue() * 255.0).round() as u32;
+        (r << 16) | (g << 8) | b
+    }
+
     #[cfg(target_os = "linux")]
     fn get_gtk_dark_mode() -> bool {
+        use gtk::prelude::*;
+        use gtk::Settings;
+
         if gtk::init().is_err() {
             return false;
         }
@@ -403,6 +637,534 @@ impl Theme {
             .and_then(|s| s.gtk_application_prefer_dark_theme())
             .unwrap_or(false)
     }
+
+    #[cfg(target_os = "linux")]
+    fn linux_with_preferences(is_dark: bool, palette: GtkPalette) -> Self {
+        let accent = palette.accent.unwrap_or(0x3584E4);
+        let accent_hover = Self::accent_hover_color(accent);
+
+        // Fall back to the same light/dark defaults used elsewhere when
+        // the theme doesn't expose `theme_fg_color`/`theme_base_color`.
+        let fg = palette
+            .fg
+            .unwrap_or(if is_dark { 0xFFFFFF } else { 0x000000 });
+        let base = palette
+            .base
+            .unwrap_or(if is_dark { 0x2D2D2D } else { 0xFFFFFF });
+        let text_secondary = rgb(if Self::relative_luminance(fg) > 0.179 {
+            Self::darken_color(fg, 0.7)
+        } else {
+            Self::lighten_color(fg, 0.4)
+        })
+        .into();
+
+        Self {
+            titlebar_bg: rgb(base).into(),
+            titlebar_border: rgb(Self::darken_color(base, 0.9)).into(),
+            titlebar_height: 24.0,
+            background_appearance: WindowBackgroundAppearance::Opaque,
+
+            close_button_bg: rgb(0xE95420).into(),
+            close_button_corner: rgb(0xC7431B).into(),
+            minimize_button_bg: rgb(base).into(),
+            minimize_button_corner: rgb(Self::darken_color(base, 0.9)).into(),
+            maximize_button_bg: rgb(base).into(),
+            maximize_button_corner: rgb(Self::darken_color(base, 0.9)).into(),
+
+            background: if is_dark {
+                rgb(0x1E1E1E).into()
+            } else {
+                rgb(0xEFEFEF).into()
+            },
+
+            input_bg: rgb(base).into(),
+            input_border: rgb(Self::darken_color(base, 0.85)).into(),
+            input_border_focused: rgb(accent).into(),
+            input_label_focused: Self::ideal_text_on(accent),
+            input_text: rgb(fg).into(),
+
+            button_primary_bg: rgb(accent).into(),
+            button_primary_bg_hover: rgb(accent_hover).into(),
+            button_primary_text: Self::ideal_text_on(accent),
+            button_secondary_bg: rgb(base).into(),
+            button_secondary_bg_hover: rgb(Self::darken_color(base, 0.95)).into(),
+            button_secondary_text: rgb(fg).into(),
+            button_secondary_border: rgb(Self::darken_color(base, 0.8)).into(),
+
+            text_primary: rgb(fg).into(),
+            text_secondary,
+            text_error: rgb(0xE01B24).into(),
+        }
+    }
+}
+
+// ======================================================
+// LIVE THEME WATCHING
+// ======================================================
+//
+// TUTORIAL: Entities & Background Notifications
+// -----------------------------------------------
+// `Theme::new(platform)` on its own is a one-shot snapshot of the OS
+// appearance at the moment it is called. To react to the user flipping the OS appearance (or changing their accent
+// color) while the app is open, we need somewhere to *keep* the theme that
+// views can observe, plus a way to push updates into it from outside GPUI's
+// normal render loop.
+//
+// `ThemeWatcher` is a plain GPUI entity: it owns the active `Theme`, and
+// each platform's `watch_*` function hooks into the OS's native
+// "appearance changed" notification, hops back onto GPUI's executor, and
+// calls `cx.notify()` so every view reading this entity re-renders.
+
+/// An entity that holds the currently active `Theme` and keeps it fresh by
+/// subscribing to OS appearance-change notifications. Views should read
+/// `theme_watcher.read(cx).theme` each render instead of calling
+/// `Theme::new` themselves, so a system-wide light/dark toggle or accent
+/// color change is reflected live.
+#[allow(dead_code)]
+struct ThemeWatcher {
+    platform: Platform,
+    theme: Theme,
+}
+
+impl ThemeWatcher {
+    /// Creates the watcher, seeds it with the current system theme, and
+    /// starts listening for OS appearance notifications for the detected
+    /// platform.
+    fn new(cx: &mut App) -> Entity<Self> {
+        let platform = Platform::detect();
+        let watcher = cx.new(|_cx| Self {
+            platform,
+            theme: Theme::new(platform),
+        });
+
+        match platform {
+            Platform::MacOS => Self::watch_macos(&watcher, cx),
+            Platform::Windows => Self::watch_windows(&watcher, cx),
+            Platform::Linux => Self::watch_linux(&watcher, cx),
+        }
+
+        watcher
+    }
+
+    /// Rebuilds the theme from scratch and notifies every observer, so
+    /// this is the single place a platform watcher needs to call into
+    /// after an OS appearance/accent notification fires.
+    fn refresh(&mut self, cx: &mut Context<Self>) {
+        self.theme = Theme::new(self.platform);
+        cx.notify();
+    }
+
+    // macOS: observe `effectiveAppearance`/`NSSystemColorsDidChangeNotification`
+    // and `controlAccentColor` via NSDistributedNotificationCenter. Cocoa
+    // notifications are delivered on whatever thread posts them, so we park
+    // a background thread on a tiny Obj-C shim object and bounce the result
+    // back onto GPUI's executor with `AsyncApp::update`.
+    #[cfg(target_os = "macos")]
+    fn watch_macos(watcher: &Entity<Self>, cx: &mut App) {
+        use cocoa::base::{id, nil};
+        use cocoa::foundation::NSString;
+        use objc::declare::ClassDecl;
+        use objc::runtime::{Object, Sel};
+        use objc::{class, msg_send, sel, sel_impl};
+        use std::sync::mpsc;
+
+        let (tx, rx) = mpsc::channel::<()>();
+        let boxed_tx = Box::into_raw(Box::new(tx));
+
+        unsafe {
+            // Declare a minimal NSObject subclass whose only job is to
+            // forward `themeChanged:` notifications onto our channel.
+            let superclass = class!(NSObject);
+            let mut decl = ClassDecl::new("GpuiThemeObserver", superclass)
+                .expect("ThemeObserver class already registered");
+            decl.add_ivar::<*mut std::ffi::c_void>("tx");
+            extern "C" fn theme_changed(this: &Object, _: Sel, _: id) {
+                unsafe {
+                    let tx_ptr =
+                        *this.get_ivar::<*mut std::ffi::c_void>("tx") as *const mpsc::Sender<()>;
+                    let _ = (*tx_ptr).send(());
+                }
+            }
+            decl.add_method(
+                sel!(themeChanged:),
+                theme_changed as extern "C" fn(&Object, Sel, id),
+            );
+            let observer_class = decl.register();
+
+            let observer: id = msg_send![observer_class, alloc];
+            let observer: id = msg_send![observer, init];
+            (*observer).set_ivar("tx", boxed_tx as *mut std::ffi::c_void);
+
+            let center: id = msg_send![class!(NSDistributedNotificationCenter), defaultCenter];
+            let names = [
+                "AppleInterfaceThemeChangedNotification",
+                "AppleColorPreferencesChangedNotification",
+                "NSSystemColorsDidChangeNotification",
+            ];
+            for name in names {
+                let ns_name = NSString::alloc(nil).init_str(name);
+                let _: () = msg_send![center,
+                    addObserver: observer
+                    selector: sel!(themeChanged:)
+                    name: ns_name
+                    object: nil
+                ];
+            }
+        }
+
+        let watcher = watcher.clone();
+        let async_cx = cx.to_async();
+        std::thread::spawn(move || {
+            while rx.recv().is_ok() {
+                let watcher = watcher.clone();
+                let _ = async_cx.update(|cx| {
+                    watcher.update(cx, |state, cx| state.refresh(cx));
+                });
+            }
+        });
+    }
+
+    // Windows: a message-only window is the standard way to receive
+    // `WM_SETTINGCHANGE` (light/dark toggle) and
+    // `WM_DWMCOLORIZATIONCOLORCHANGED` (accent color) without a visible
+    // window of our own.
+    #[cfg(target_os = "windows")]
+    fn watch_windows(watcher: &Entity<Self>, cx: &mut App) {
+        use windows::Win32::Foundation::{HWND, LPARAM, LRESULT, WPARAM};
+        use windows::Win32::UI::WindowsAndMessaging::{
+            CreateWindowExW, DefWindowProcW, DispatchMessageW, GetMessageW, RegisterClassW,
+            TranslateMessage, HWND_MESSAGE, MSG, WINDOW_EX_STYLE, WNDCLASSW, WS_OVERLAPPED,
+        };
+
+        let watcher = watcher.clone();
+        let async_cx = cx.to_async();
+
+        std::thread::spawn(move || unsafe {
+            unsafe extern "system" fn wndproc(
+                hwnd: HWND,
+                msg: u32,
+                wparam: WPARAM,
+                lparam: LPARAM,
+            ) -> LRESULT {
+                const WM_SETTINGCHANGE: u32 = 0x001A;
+                const WM_DWMCOLORIZATIONCOLORCHANGED: u32 = 0x0320;
+                if msg == WM_SETTINGCHANGE || msg == WM_DWMCOLORIZATIONCOLORCHANGED {
+                    if let Some(tx) = THEME_CHANGE_TX.with(|cell| cell.borrow().clone()) {
+                        let _ = tx.send(());
+                    }
+                }
+                DefWindowProcW(hwnd, msg, wparam, lparam)
+            }
+
+            thread_local! {
+                static THEME_CHANGE_TX: std::cell::RefCell<Option<std::sync::mpsc::Sender<()>>> =
+                    std::cell::RefCell::new(None);
+            }
+
+            let (tx, rx) = std::sync::mpsc::channel::<()>();
+            THEME_CHANGE_TX.with(|cell| *cell.borrow_mut() = Some(tx));
+
+            let class_name = windows::core::w!("GpuiThemeWatcherWindow");
+            let wc = WNDCLASSW {
+                lpfnWndProc: Some(wndproc),
+                lpszClassName: class_name,
+                ..Default::default()
+            };
+            RegisterClassW(&wc);
+            let _hwnd = CreateWindowExW(
+                WINDOW_EX_STYLE(0),
+                class_name,
+                windows::core::w!(""),
+                WS_OVERLAPPED,
+                0,
+                0,
+                0,
+                0,
+                HWND_MESSAGE,
+                None,
+                None,
+                None,
+            );
+
+            // Drain the OS message queue on this thread so `wndproc` above
+            // keeps getting called; forward each settings change to GPUI.
+            let forwarder_watcher = watcher.clone();
+            let forwarder_cx = async_cx.clone();
+            std::thread::spawn(move || {
+                while rx.recv().is_ok() {
+                    let watcher = forwarder_watcher.clone();
+                    let _ = forwarder_cx.update(|cx| {
+                        watcher.update(cx, |state, cx| state.refresh(cx));
+                    });
+                }
+            });
+
+            let mut msg = MSG::default();
+            while GetMessageW(&mut msg, None, 0, 0).as_bool() {
+                let _ = TranslateMessage(&msg);
+                DispatchMessageW(&msg);
+            }
+        });
+    }
+
+    // Linux: GTK themes are just a `Settings` property; listening for
+    // `notify::gtk-application-prefer-dark-theme` and
+    // `notify::gtk-theme-name` covers both a light/dark toggle and a full
+    // theme swap (which can also change the accent color).
+    #[cfg(target_os = "linux")]
+    fn watch_linux(watcher: &Entity<Self>, cx: &mut App) {
+        use gtk::prelude::*;
+        use gtk::Settings;
+
+        let Some(settings) = Settings::default() else {
+            return;
+        };
+
+        let watcher = watcher.clone();
+        let async_cx = cx.to_async();
+        let notify = move || {
+            let watcher = watcher.clone();
+            let _ = async_cx.update(|cx| {
+                watcher.update(cx, |state, cx| state.refresh(cx));
+            });
+        };
+
+        let notify_dark = notify.clone();
+        settings.connect_notify_local(Some("gtk-application-prefer-dark-theme"), move |_, _| {
+            notify_dark();
+        });
+        settings.connect_notify_local(Some("gtk-theme-name"), move |_, _| {
+            notify();
+        });
+
+        // `connect_notify_local` only ever fires while something is
+        // iterating GLib's default main context, but GPUI's Linux backend
+        // drives its own calloop event loop and never touches GLib's. Pump
+        // the context on a dedicated thread so the `notify::*` signals
+        // above actually get dispatched.
+        std::thread::spawn(|| {
+            let main_context = gtk::glib::MainContext::default();
+            loop {
+                main_context.iteration(true);
+            }
+        });
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    fn watch_macos(_watcher: &Entity<Self>, _cx: &mut App) {}
+
+    #[cfg(not(target_os = "windows"))]
+    fn watch_windows(_watcher: &Entity<Self>, _cx: &mut App) {}
+
+    #[cfg(not(target_os = "linux"))]
+    fn watch_linux(_watcher: &Entity<Self>, _cx: &mut App) {}
+}
+
+// ======================================================
+// TITLE BAR COMPONENT
+// ======================================================
+//
+// TUTORIAL: Composing a Native-Looking Custom Chrome
+// ---------------------------------------------------
+// `Theme` carries all the colors a title bar needs
+// (`close_button_bg`/`_corner`, etc.) but nothing ever turned that data
+// into an actual view. `TitleBar` is that view: it lays out macOS traffic
+// lights or Windows-style min/max/close controls depending on `Platform`,
+// wires each button to the real window action it represents, and treats
+// the rest of the bar as a drag handle for moving the window - the same
+// contract every custom-chrome GPUI app follows.
+
+/// Identifies which window control the pointer is currently over, so hover
+/// colors only apply to the control being hovered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(dead_code)]
+enum WindowControl {
+    Close,
+    Minimize,
+    Maximize,
+}
+
+/// A reusable title bar. Drop `TitleBar::new(platform, theme)` into the top
+/// of a window's render tree to get native-looking traffic lights
+/// (macOS) or min/max/close buttons (Windows/Linux), real click handlers,
+/// and a draggable background.
+#[allow(dead_code)]
+struct TitleBar {
+    platform: Platform,
+    theme: Theme,
+    hovered: Option<WindowControl>,
 }
 
+#[allow(dead_code)]
+impl TitleBar {
+    fn new(platform: Platform, theme: Theme) -> Self {
+        Self {
+            platform,
+            theme,
+            hovered: None,
+        }
+    }
+
+    fn set_hovered(&mut self, control: Option<WindowControl>, cx: &mut Context<Self>) {
+        if self.hovered != control {
+            self.hovered = control;
+            cx.notify();
+        }
+    }
 
+    fn control_colors(&self, control: WindowControl) -> (Hsla, Hsla) {
+        match control {
+            WindowControl::Close => (self.theme.close_button_bg, self.theme.close_button_corner),
+            WindowControl::Minimize => (
+                self.theme.minimize_button_bg,
+                self.theme.minimize_button_corner,
+            ),
+            WindowControl::Maximize => (
+                self.theme.maximize_button_bg,
+                self.theme.maximize_button_corner,
+            ),
+        }
+    }
+
+    // A single, round (macOS) or square (Windows/Linux) window control.
+    // Its fill switches to the theme's "corner" shade while hovered, and
+    // its click handler performs the real window action.
+    fn render_control(&self, control: WindowControl, cx: &mut Context<Self>) -> impl IntoElement {
+        let (bg, hover_bg) = self.control_colors(control);
+        let is_hovered = self.hovered == Some(control);
+        let is_mac = self.platform == Platform::MacOS;
+
+        div()
+            .id(match control {
+                WindowControl::Close => "titlebar-close",
+                WindowControl::Minimize => "titlebar-minimize",
+                WindowControl::Maximize => "titlebar-maximize",
+            })
+            .flex()
+            .items_center()
+            .justify_center()
+            .when(is_mac, |style| {
+                style.size(px(12.0)).rounded_full().mx(px(4.0))
+            })
+            .when(!is_mac, |style| style.w(px(46.0)).h_full())
+            .bg(if is_hovered { hover_bg } else { bg })
+            .on_hover(cx.listener(move |this, hovered, _window, cx| {
+                this.set_hovered(if *hovered { Some(control) } else { None }, cx);
+            }))
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(move |_this, _event, window, cx| {
+                    match control {
+                        WindowControl::Close => window.remove(),
+                        WindowControl::Minimize => window.minimize(),
+                        WindowControl::Maximize => window.toggle_maximized(),
+                    }
+                    // The titlebar root also registers a `MouseButton::Left`
+                    // down handler to drag-move the window; without this the
+                    // same press would both trigger the control AND start a
+                    // window move.
+                    cx.stop_propagation();
+                }),
+            )
+    }
+
+    fn render_macos_controls(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .pl(px(8.0))
+            .child(self.render_control(WindowControl::Close, cx))
+            .child(self.render_control(WindowControl::Minimize, cx))
+            .child(self.render_control(WindowControl::Maximize, cx))
+    }
+
+    fn render_windows_controls(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .h_full()
+            .child(self.render_control(WindowControl::Minimize, cx))
+            .child(self.render_control(WindowControl::Maximize, cx))
+            .child(self.render_control(WindowControl::Close, cx))
+    }
+}
+
+impl Render for TitleBar {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let is_mac = self.platform == Platform::MacOS;
+
+        div()
+            .id("titlebar")
+            .flex()
+            .flex_row()
+            .w_full()
+            .h(px(self.theme.titlebar_height))
+            .bg(self.theme.titlebar_bg)
+            .border_b_1()
+            .border_color(self.theme.titlebar_border)
+            .when(is_mac, |style| style.justify_start())
+            .when(!is_mac, |style| style.justify_end())
+            // The bar itself (everything that isn't a button) is the drag
+            // handle used to move the window, matching how native title
+            // bars behave.
+            .on_mouse_down(
+                MouseButton::Left,
+                cx.listener(|_this, _event, window, _cx| {
+                    window.start_window_move();
+                }),
+            )
+            .child(if is_mac {
+                self.render_macos_controls(cx).into_any_element()
+            } else {
+                self.render_windows_controls(cx).into_any_element()
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ideal_text_on_picks_near_black_for_a_white_surface() {
+        assert_eq!(Theme::ideal_text_on(0xFFFFFF), rgb(0x1A1A1A).into());
+    }
+
+    #[test]
+    fn ideal_text_on_picks_near_white_for_a_black_surface() {
+        assert_eq!(Theme::ideal_text_on(0x000000), rgb(0xFFFFFF).into());
+    }
+
+    #[test]
+    fn lighten_color_is_a_no_op_at_white() {
+        assert_eq!(Theme::lighten_color(0xFFFFFF, 0.5), 0xFFFFFF);
+    }
+
+    #[test]
+    fn darken_color_is_a_no_op_at_black() {
+        assert_eq!(Theme::darken_color(0x000000, 0.5), 0x000000);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn gdk_rgba_to_u32_round_trips_each_channel() {
+        let rgba = gtk::gdk::RGBA::new(
+            0x33 as f64 / 255.0,
+            0x66 as f64 / 255.0,
+            0x99 as f64 / 255.0,
+            1.0,
+        );
+        assert_eq!(Theme::gdk_rgba_to_u32(rgba), 0x336699);
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn read_personalize_dword_returns_none_for_a_missing_value() {
+        assert_eq!(
+            Theme::read_personalize_dword("ThisValueDoesNotExist12345"),
+            None
+        );
+    }
+}